@@ -0,0 +1,104 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use reqwest::Method;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::client::money::Money;
+use crate::client::prices::Price;
+use crate::client::Client;
+use crate::error::Error;
+
+/// The Invoice resource represents an invoice issued to a customer, broken down into the line items that make up its total.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Invoice {
+    /// The unique identifier for the invoice.
+    pub id: Option<String>,
+    /// The status of the invoice (e.g., `draft`, `issued`, `paid`).
+    pub status: String,
+    /// The ISO 4217 currency code the invoice is billed in.
+    pub currency: String,
+    /// The amount remaining to be paid on the invoice.
+    pub amount_due: Money,
+    /// The total of all line items on the invoice.
+    pub total: Money,
+    /// The line items that make up the invoice.
+    #[serde(default = "Vec::new")]
+    pub line_items: Vec<InvoiceLineItem>,
+}
+
+impl<'de> Deserialize<'de> for Invoice {
+    fn deserialize<D>(deserializer: D) -> Result<Invoice, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The invoice amounts arrive as bare decimal strings; backfill their
+        // currency from the invoice's own `currency` field after deserializing.
+        #[derive(Deserialize)]
+        struct Raw {
+            id: Option<String>,
+            status: String,
+            currency: String,
+            amount_due: Money,
+            total: Money,
+            #[serde(default = "Vec::new")]
+            line_items: Vec<InvoiceLineItem>,
+        }
+        let mut raw = Raw::deserialize(deserializer)?;
+        raw.amount_due.currency = raw.currency.clone();
+        raw.total.currency = raw.currency.clone();
+        for item in &mut raw.line_items {
+            item.amount.currency = raw.currency.clone();
+        }
+        Ok(Invoice {
+            id: raw.id,
+            status: raw.status,
+            currency: raw.currency,
+            amount_due: raw.amount_due,
+            total: raw.total,
+            line_items: raw.line_items,
+        })
+    }
+}
+
+/// A single line item on an [`Invoice`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct InvoiceLineItem {
+    /// The unique identifier for the line item.
+    pub id: String,
+    /// A human-readable name for the line item.
+    pub name: String,
+    /// The quantity of the price that was billed.
+    pub quantity: f64,
+    /// The amount charged for the line item. Its currency is backfilled from
+    /// the enclosing [`Invoice`].
+    pub amount: Money,
+    /// The price that generated the line item, if any.
+    pub price: Option<Price>,
+}
+
+impl Client {
+    /// Fetch the upcoming invoice for a subscription.
+    ///
+    /// The upcoming invoice reflects the charges that would be billed on the
+    /// subscription's next invoice date given its current price intervals and
+    /// adjustments, allowing callers to preview a total before it is issued.
+    pub async fn fetch_upcoming_invoice(&self, subscription_id: &str) -> Result<Invoice, Error> {
+        let req = self.build_request(Method::GET, ["invoices", "upcoming"]);
+        let req = req.query(&[("subscription_id", subscription_id)]);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A monetary amount tagged with the currency it is denominated in.
+///
+/// Orb represents amounts as bare decimal strings, with the currency carried by
+/// the enclosing resource. `Money` (de)serializes as that string and keeps the
+/// two together in memory so arithmetic on billed amounts is exact and currency
+/// mismatches are rejected rather than silently summed. On the read path the
+/// `currency` is backfilled from the surrounding resource's `currency` field by
+/// that resource's `Deserialize` implementation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Money {
+    /// The decimal amount. This is the only part carried on the wire.
+    pub amount: Decimal,
+    /// The ISO 4217 currency code the amount is denominated in.
+    pub currency: String,
+}
+
+impl Serialize for Money {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        rust_decimal::serde::str::serialize(&self.amount, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D>(deserializer: D) -> Result<Money, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let amount = rust_decimal::serde::str::deserialize(deserializer)?;
+        Ok(Money {
+            amount,
+            currency: String::new(),
+        })
+    }
+}
+
+impl Money {
+    /// Creates a new amount in the given ISO 4217 currency.
+    pub fn new(amount: Decimal, currency: impl Into<String>) -> Money {
+        Money {
+            amount,
+            currency: currency.into(),
+        }
+    }
+
+    /// Adds two amounts, returning an error if their currencies differ.
+    pub fn checked_add(&self, other: &Money) -> Result<Money, CurrencyMismatch> {
+        self.check_currency(other)?;
+        Ok(Money::new(self.amount + other.amount, &self.currency))
+    }
+
+    /// Subtracts `other` from `self`, returning an error if their currencies differ.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, CurrencyMismatch> {
+        self.check_currency(other)?;
+        Ok(Money::new(self.amount - other.amount, &self.currency))
+    }
+
+    fn check_currency(&self, other: &Money) -> Result<(), CurrencyMismatch> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(CurrencyMismatch {
+                left: self.currency.clone(),
+                right: other.currency.clone(),
+            })
+        }
+    }
+}
+
+/// The error returned when combining two [`Money`] values of different currencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyMismatch {
+    /// The currency of the left-hand amount.
+    pub left: String,
+    /// The currency of the right-hand amount.
+    pub right: String,
+}
+
+impl fmt::Display for CurrencyMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot combine amounts in different currencies: {} and {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl std::error::Error for CurrencyMismatch {}
@@ -13,6 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::client::invoices::Invoice;
+use crate::client::money::Money;
+use crate::client::prices::NewPrice;
 use crate::client::subscriptions::Subscription;
 use crate::client::Client;
 use crate::error::Error;
@@ -26,15 +29,88 @@ pub struct AddEditPriceIntervalParams<'a> {
     /// A list of adjustments to add to the subscription.
     #[serde(default = "Vec::new")]
     pub add_adjustments: Vec<AddAdjustmentIntervalParams<'a>>,
-    /// Not implemented
+    /// A list of price intervals to add to the subscription.
     #[serde(default = "Vec::new")]
-    pub add: Vec<()>,
-    /// Not implemented
+    pub add: Vec<AddPriceIntervalParams<'a>>,
+    /// A list of price intervals on the subscription to edit.
     #[serde(default = "Vec::new")]
-    pub edit: Vec<()>,
-    /// Not implemented
+    pub edit: Vec<EditPriceIntervalParams<'a>>,
+    /// A list of adjustment intervals on the subscription to edit.
     #[serde(default = "Vec::new")]
-    pub edit_adjustments: Vec<()>,
+    pub edit_adjustments: Vec<EditAdjustmentIntervalParams<'a>>,
+    /// When set, the changes are not applied; the endpoint instead returns the
+    /// invoice that would result from them. See [`Client::preview_price_intervals`].
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+}
+
+/// Parameters for adding a new price interval to a subscription.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AddPriceIntervalParams<'a> {
+    /// The ID of an existing price to add. Mutually exclusive with `price`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_id: Option<&'a str>,
+    /// The definition of a new price to create and add inline. Mutually exclusive with `price_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<NewPrice<'a>>,
+    /// The date the price interval starts billing.
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_date: OffsetDateTime,
+    /// The date the price interval stops billing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+    /// A filter limiting the usage events the price applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<&'a str>,
+    /// A schedule of changes to the price's fixed-fee quantity over time.
+    #[serde(default = "Vec::new")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixed_fee_quantity_transitions: Vec<FixedFeeQuantityTransition>,
+}
+
+/// Parameters for editing an existing price interval on a subscription.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EditPriceIntervalParams<'a> {
+    /// The ID of the price interval to edit.
+    pub price_interval_id: &'a str,
+    /// The new date the price interval starts billing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub start_date: Option<OffsetDateTime>,
+    /// The new date the price interval stops billing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+    /// A schedule of changes to the price's fixed-fee quantity over time.
+    #[serde(default = "Vec::new")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixed_fee_quantity_transitions: Vec<FixedFeeQuantityTransition>,
+}
+
+/// Parameters for editing an existing adjustment interval on a subscription.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EditAdjustmentIntervalParams<'a> {
+    /// The ID of the adjustment interval to edit.
+    pub adjustment_interval_id: &'a str,
+    /// The new date the adjustment interval starts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub start_date: Option<OffsetDateTime>,
+    /// The new date the adjustment interval ends.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+}
+
+/// A scheduled change to the fixed-fee quantity billed by a price interval.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FixedFeeQuantityTransition {
+    /// The date the new quantity takes effect.
+    #[serde(with = "time::serde::rfc3339")]
+    pub effective_date: OffsetDateTime,
+    /// The quantity billed from `effective_date` onward.
+    pub quantity: f64,
 }
 
 /// Parameters for adding a new adjustment interval to a subscription.
@@ -69,8 +145,35 @@ pub enum NewAdjustment<'a> {
     AmountDiscount {
         /// The IDs of the prices to which this discount applies.
         applies_to_price_ids: Vec<&'a str>,
-        /// The fixed amount to discount, represented as a string (e.g., "10.00").
-        amount_discount: &'a str,
+        /// The fixed amount to discount.
+        amount_discount: Money,
+    },
+    /// A usage-rate discount adjustment.
+    #[serde(rename = "usage_discount")]
+    UsageDiscount {
+        /// The IDs of the prices to which this discount applies.
+        applies_to_price_ids: Vec<&'a str>,
+        /// The number of usage units to discount.
+        usage_discount: f64,
+    },
+    /// A minimum spend floor applied to a set of prices.
+    #[serde(rename = "minimum")]
+    Minimum {
+        /// The IDs of the prices to which this minimum applies.
+        applies_to_price_ids: Vec<&'a str>,
+        /// The minimum amount to charge.
+        minimum_amount: Money,
+        /// The ID of the item the minimum is associated with, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        item_id: Option<&'a str>,
+    },
+    /// A maximum spend cap applied to a set of prices.
+    #[serde(rename = "maximum")]
+    Maximum {
+        /// The IDs of the prices to which this maximum applies.
+        applies_to_price_ids: Vec<&'a str>,
+        /// The maximum amount to charge.
+        maximum_amount: Money,
     },
 }
 
@@ -93,4 +196,26 @@ impl Client {
         let res = self.send_request(req).await?;
         Ok(res)
     }
+
+    /// Preview the effect of price-interval changes without applying them.
+    ///
+    /// This sends the same request as [`Client::add_edit_price_intervals`] with
+    /// `dry_run` forced on, returning the [`Invoice`] that the changes would
+    /// produce so callers can compute the billing delta before committing.
+    pub async fn preview_price_intervals(
+        &self,
+        subscription_id: &str,
+        params: &AddEditPriceIntervalParams<'_>,
+    ) -> Result<Invoice, Error> {
+        let req = self.build_request(
+            Method::POST,
+            ["subscriptions", subscription_id, "price_intervals"],
+        );
+        let req = req.json(&AddEditPriceIntervalParams {
+            dry_run: true,
+            ..params.clone()
+        });
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
 }
@@ -13,14 +13,229 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use serde::{Deserialize, Serialize};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::client::money::Money;
 
 /// The Price resource represents a price that can be billed on a subscription, resulting in a charge on an invoice in the form of an invoice line item. Prices take a quantity and determine an amount to bill.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct Price {
     /// The unique identifier for the price.
     pub id: String,
     /// The external identifier for the price.
     pub external_price_id: Option<String>,
-    // TODO: many missing fields.
-}
\ No newline at end of file
+    /// A human-readable name for the price.
+    pub name: String,
+    /// The ISO 4217 currency code the price is billed in.
+    pub currency: String,
+    /// The cadence at which the price recurs.
+    pub cadence: Cadence,
+    /// The ID of the billable metric the price is computed from, if any.
+    pub billable_metric_id: Option<String>,
+    /// For fixed prices, the quantity that is billed each period.
+    pub fixed_price_quantity: Option<Decimal>,
+    /// The model that determines how the price computes a charge from a quantity.
+    #[serde(flatten)]
+    pub model: PriceModel,
+}
+
+impl<'de> Deserialize<'de> for Price {
+    fn deserialize<D>(deserializer: D) -> Result<Price, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The amounts in `model` arrive as bare decimal strings; backfill their
+        // currency from the price's own `currency` field after deserializing.
+        #[derive(Deserialize)]
+        struct Raw {
+            id: String,
+            external_price_id: Option<String>,
+            name: String,
+            currency: String,
+            cadence: Cadence,
+            billable_metric_id: Option<String>,
+            fixed_price_quantity: Option<Decimal>,
+            #[serde(flatten)]
+            model: PriceModel,
+        }
+        let mut raw = Raw::deserialize(deserializer)?;
+        raw.model.set_currency(&raw.currency);
+        Ok(Price {
+            id: raw.id,
+            external_price_id: raw.external_price_id,
+            name: raw.name,
+            currency: raw.currency,
+            cadence: raw.cadence,
+            billable_metric_id: raw.billable_metric_id,
+            fixed_price_quantity: raw.fixed_price_quantity,
+            model: raw.model,
+        })
+    }
+}
+
+/// The cadence at which a price recurs on a subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Cadence {
+    /// The price is billed once, at the start of the subscription.
+    OneTime,
+    /// The price is billed every month.
+    Monthly,
+    /// The price is billed every three months.
+    Quarterly,
+    /// The price is billed every six months.
+    SemiAnnual,
+    /// The price is billed once per year.
+    Annual,
+}
+
+/// Describes how a [`Price`] turns a billed quantity into a charge.
+///
+/// The variants correspond to Orb's `model_type` discriminant, with the
+/// per-model configuration nested under a `*_config` object exactly as Orb
+/// sends it (`unit_config`, `tiered_config`, and so on).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(tag = "model_type")]
+pub enum PriceModel {
+    /// Charges a flat amount per unit.
+    #[serde(rename = "unit")]
+    Unit {
+        /// The unit pricing configuration.
+        unit_config: UnitConfig,
+    },
+    /// Charges a per-unit amount that varies by graduated tiers of usage.
+    #[serde(rename = "tiered")]
+    Tiered {
+        /// The tiered pricing configuration.
+        tiered_config: TieredConfig,
+    },
+    /// Charges a flat amount for each block of `package_size` units.
+    #[serde(rename = "package")]
+    Package {
+        /// The package pricing configuration.
+        package_config: PackageConfig,
+    },
+    /// Charges a single per-unit amount determined by the tier the total usage falls into.
+    #[serde(rename = "bulk")]
+    Bulk {
+        /// The bulk pricing configuration.
+        bulk_config: BulkConfig,
+    },
+    /// Charges a per-unit amount looked up from a matrix of dimension values.
+    #[serde(rename = "matrix")]
+    Matrix {
+        /// The matrix pricing configuration.
+        matrix_config: MatrixConfig,
+    },
+}
+
+impl PriceModel {
+    /// Backfills the currency of every amount in the model from the enclosing
+    /// price's `currency`, which Orb carries separately from the amounts.
+    fn set_currency(&mut self, currency: &str) {
+        match self {
+            PriceModel::Unit { unit_config } => {
+                unit_config.unit_amount.currency = currency.to_string();
+            }
+            PriceModel::Tiered { tiered_config } => {
+                for tier in &mut tiered_config.tiers {
+                    tier.unit_amount.currency = currency.to_string();
+                }
+            }
+            PriceModel::Package { package_config } => {
+                package_config.package_amount.currency = currency.to_string();
+            }
+            PriceModel::Bulk { bulk_config } => {
+                for tier in &mut bulk_config.tiers {
+                    tier.unit_amount.currency = currency.to_string();
+                }
+            }
+            PriceModel::Matrix { matrix_config } => {
+                matrix_config.default_unit_amount.currency = currency.to_string();
+                for value in &mut matrix_config.matrix_values {
+                    value.unit_amount.currency = currency.to_string();
+                }
+            }
+        }
+    }
+}
+
+/// The configuration of a [`PriceModel::Unit`] price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UnitConfig {
+    /// The amount to charge for each unit.
+    pub unit_amount: Money,
+}
+
+/// The configuration of a [`PriceModel::Tiered`] price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct TieredConfig {
+    /// The tiers, ordered from the lowest range of units to the highest.
+    pub tiers: Vec<PriceTier>,
+}
+
+/// The configuration of a [`PriceModel::Package`] price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PackageConfig {
+    /// The amount to charge for each package.
+    pub package_amount: Money,
+    /// The number of units that make up a single package.
+    pub package_size: Decimal,
+}
+
+/// The configuration of a [`PriceModel::Bulk`] price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BulkConfig {
+    /// The tiers, ordered from the lowest range of units to the highest.
+    pub tiers: Vec<PriceTier>,
+}
+
+/// The configuration of a [`PriceModel::Matrix`] price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct MatrixConfig {
+    /// The event property dimensions used to look up a matrix value.
+    pub dimensions: Vec<String>,
+    /// The amount to charge when no matrix value matches.
+    pub default_unit_amount: Money,
+    /// The per-unit amounts keyed by dimension values.
+    pub matrix_values: Vec<MatrixValue>,
+}
+
+/// The definition of a new price to create inline, e.g. when adding a price
+/// interval to a subscription without first registering the price separately.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NewPrice<'a> {
+    /// A human-readable name for the price.
+    pub name: &'a str,
+    /// The ID of the item the price belongs to.
+    pub item_id: &'a str,
+    /// The cadence at which the price recurs.
+    pub cadence: Cadence,
+    /// The ISO 4217 currency code the price is billed in.
+    pub currency: &'a str,
+    /// The model that determines how the price computes a charge from a quantity.
+    #[serde(flatten)]
+    pub model: PriceModel,
+}
+
+/// A single tier of a [`PriceModel::Tiered`] or [`PriceModel::Bulk`] price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PriceTier {
+    /// The first unit, inclusive, covered by this tier.
+    pub first_unit: Decimal,
+    /// The last unit, inclusive, covered by this tier. `None` for the final, unbounded tier.
+    pub last_unit: Option<Decimal>,
+    /// The amount to charge for each unit in this tier.
+    pub unit_amount: Money,
+}
+
+/// A single entry in a [`PriceModel::Matrix`] price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct MatrixValue {
+    /// The dimension values this entry matches, in the same order as the price's
+    /// `dimensions`. A `None` acts as a wildcard for that dimension.
+    pub dimension_values: Vec<Option<String>>,
+    /// The amount to charge for each unit.
+    pub unit_amount: Money,
+}